@@ -1,4 +1,7 @@
-use image::{DynamicImage, ImageFormat, ImageError, imageops::FilterType};
+use image::{AnimationDecoder, DynamicImage, Frame, ImageFormat, ImageError, imageops::FilterType};
+use image::codecs::gif::GifDecoder;
+use image::codecs::webp::WebPDecoder;
+use std::io::Cursor;
 use std::path::Path;
 
 /// Error codes for image operations
@@ -26,6 +29,12 @@ pub enum ImageFormatEnum {
     Bmp = 4,
     Ico = 5,
     Tiff = 6,
+    Avif = 7,
+    Hdr = 8,
+    Farbfeld = 9,
+    Tga = 10,
+    Pnm = 11,
+    Dds = 12,
 }
 
 impl ImageFormatEnum {
@@ -38,6 +47,12 @@ impl ImageFormatEnum {
             ImageFormatEnum::Bmp => ImageFormat::Bmp,
             ImageFormatEnum::Ico => ImageFormat::Ico,
             ImageFormatEnum::Tiff => ImageFormat::Tiff,
+            ImageFormatEnum::Avif => ImageFormat::Avif,
+            ImageFormatEnum::Hdr => ImageFormat::Hdr,
+            ImageFormatEnum::Farbfeld => ImageFormat::Farbfeld,
+            ImageFormatEnum::Tga => ImageFormat::Tga,
+            ImageFormatEnum::Pnm => ImageFormat::Pnm,
+            ImageFormatEnum::Dds => ImageFormat::Dds,
         }
     }
 
@@ -50,6 +65,12 @@ impl ImageFormatEnum {
             ImageFormat::Bmp => Some(ImageFormatEnum::Bmp),
             ImageFormat::Ico => Some(ImageFormatEnum::Ico),
             ImageFormat::Tiff => Some(ImageFormatEnum::Tiff),
+            ImageFormat::Avif => Some(ImageFormatEnum::Avif),
+            ImageFormat::Hdr => Some(ImageFormatEnum::Hdr),
+            ImageFormat::Farbfeld => Some(ImageFormatEnum::Farbfeld),
+            ImageFormat::Tga => Some(ImageFormatEnum::Tga),
+            ImageFormat::Pnm => Some(ImageFormatEnum::Pnm),
+            ImageFormat::Dds => Some(ImageFormatEnum::Dds),
             _ => None,
         }
     }
@@ -85,12 +106,33 @@ pub struct ImageHandle {
     _private: [u8; 0],
 }
 
+#[allow(dead_code)]
+/// Opaque handle to a decoded animation (GIF/WebP)
+#[repr(C)]
+pub struct AnimationHandle {
+    _private: [u8; 0],
+}
+
+#[allow(dead_code)]
+/// Opaque handle to a recorded chain of operations, run via `fast_image_pipeline_apply[_batch]`
+#[repr(C)]
+pub struct PipelineHandle {
+    _private: [u8; 0],
+}
+
+/// A single decoded animation frame paired with its display delay in milliseconds
+pub struct AnimationFrame {
+    pub image: DynamicImage,
+    pub delay_ms: u32,
+}
+
 /// Image metadata structure
 #[repr(C)]
 pub struct ImageMetadata {
     pub width: u32,
     pub height: u32,
-    pub color_type: u8, // 0=L, 1=La, 2=Rgb, 3=Rgba
+    // 0=L8, 1=La8, 2=Rgb8, 3=Rgba8, 4=L16, 5=La16, 6=Rgb16, 7=Rgba16, 8=Rgb32F, 9=Rgba32F
+    pub color_type: u8,
 }
 
 #[allow(dead_code)]
@@ -120,6 +162,50 @@ pub fn load_image_from_memory_with_format(
     image::load_from_memory_with_format(data, format)
 }
 
+/// Reduce a frame's `Delay` (numerator/denominator of milliseconds) to integer milliseconds
+fn frame_to_animation_frame(frame: Frame) -> AnimationFrame {
+    let (numer, denom) = frame.delay().numer_denom_ms();
+    let delay_ms = numer.checked_div(denom).unwrap_or(0);
+
+    AnimationFrame {
+        image: DynamicImage::ImageRgba8(frame.into_buffer()),
+        delay_ms,
+    }
+}
+
+/// Decode every frame of an animated GIF or WebP from raw bytes
+fn decode_animation_frames(data: &[u8]) -> Result<Vec<AnimationFrame>, ImageError> {
+    let format = image::guess_format(data)?;
+
+    let frames = match format {
+        ImageFormat::Gif => GifDecoder::new(Cursor::new(data))?.into_frames(),
+        ImageFormat::WebP => WebPDecoder::new(Cursor::new(data))?.into_frames(),
+        _ => {
+            return Err(ImageError::Unsupported(
+                image::error::UnsupportedError::from_format_and_kind(
+                    image::error::ImageFormatHint::Exact(format),
+                    image::error::UnsupportedErrorKind::Format(
+                        image::error::ImageFormatHint::Exact(format),
+                    ),
+                ),
+            ));
+        }
+    };
+
+    frames.into_iter().map(|f| f.map(frame_to_animation_frame)).collect()
+}
+
+/// Load an animated GIF/WebP from a file path, decoding every frame
+pub fn load_animation(path: &str) -> Result<Vec<AnimationFrame>, ImageError> {
+    let data = std::fs::read(Path::new(path)).map_err(ImageError::IoError)?;
+    decode_animation_frames(&data)
+}
+
+/// Load an animated GIF/WebP from a memory buffer, decoding every frame
+pub fn load_animation_from_memory(data: &[u8]) -> Result<Vec<AnimationFrame>, ImageError> {
+    decode_animation_frames(data)
+}
+
 /// Save an image to a file path
 pub fn save_image(img: &DynamicImage, path: &str) -> Result<(), ImageError> {
     img.save(Path::new(path))
@@ -135,6 +221,125 @@ pub fn encode_image(
     Ok(buffer)
 }
 
+#[allow(dead_code)]
+/// Quality/compression parameters for `fast_image_encode_with_options`
+#[repr(C)]
+pub struct EncodeOptions {
+    /// JPEG quality, 1-100 (ignored for non-JPEG formats)
+    pub jpeg_quality: u8,
+    /// PNG compression level: 0=fast, 1=default, 2=best (ignored for non-PNG formats)
+    pub png_compression: u8,
+    /// PNG filter type: 0=none, 1=sub, 2=up, 3=avg, 4=paeth, 5=adaptive (ignored for non-PNG formats)
+    pub png_filter: u8,
+}
+
+impl Default for EncodeOptions {
+    fn default() -> Self {
+        EncodeOptions {
+            jpeg_quality: 80,
+            png_compression: 1,
+            png_filter: 5,
+        }
+    }
+}
+
+fn png_compression_type(level: u8) -> image::codecs::png::CompressionType {
+    match level {
+        0 => image::codecs::png::CompressionType::Fast,
+        2 => image::codecs::png::CompressionType::Best,
+        _ => image::codecs::png::CompressionType::Default,
+    }
+}
+
+fn png_filter_type(filter: u8) -> image::codecs::png::FilterType {
+    match filter {
+        0 => image::codecs::png::FilterType::NoFilter,
+        1 => image::codecs::png::FilterType::Sub,
+        2 => image::codecs::png::FilterType::Up,
+        3 => image::codecs::png::FilterType::Avg,
+        4 => image::codecs::png::FilterType::Paeth,
+        _ => image::codecs::png::FilterType::Adaptive,
+    }
+}
+
+/// Downgrade an image to the color type `JpegEncoder::write_image` accepts (L8/Rgb8 only),
+/// mirroring the `make_compatible_img` step that `write_to`/`write_with_encoder` normally
+/// perform automatically but that driving the encoder directly skips.
+fn jpeg_compatible_bytes(img: &DynamicImage) -> (Vec<u8>, image::ExtendedColorType) {
+    match img.color() {
+        image::ColorType::L8 | image::ColorType::La8 | image::ColorType::L16 | image::ColorType::La16 => {
+            (img.to_luma8().into_raw(), image::ExtendedColorType::L8)
+        }
+        _ => (img.to_rgb8().into_raw(), image::ExtendedColorType::Rgb8),
+    }
+}
+
+/// Downgrade an image to a color type `PngEncoder::write_image` accepts, same rationale as
+/// `jpeg_compatible_bytes`. Unlike JPEG, PNG natively supports 16-bit depth, so the 16-bit
+/// variants are preserved; only the float (`Rgb32F`/`Rgba32F`) variants fall back to 8-bit.
+fn png_compatible_bytes(img: &DynamicImage) -> (Vec<u8>, image::ExtendedColorType) {
+    match img.color() {
+        image::ColorType::L8 => (img.to_luma8().into_raw(), image::ExtendedColorType::L8),
+        image::ColorType::La8 => {
+            (img.to_luma_alpha8().into_raw(), image::ExtendedColorType::La8)
+        }
+        image::ColorType::Rgb8 => (img.to_rgb8().into_raw(), image::ExtendedColorType::Rgb8),
+        image::ColorType::L16 => (
+            DynamicImage::ImageLuma16(img.to_luma16()).as_bytes().to_vec(),
+            image::ExtendedColorType::L16,
+        ),
+        image::ColorType::La16 => (
+            DynamicImage::ImageLumaA16(img.to_luma_alpha16()).as_bytes().to_vec(),
+            image::ExtendedColorType::La16,
+        ),
+        image::ColorType::Rgb16 => (
+            DynamicImage::ImageRgb16(img.to_rgb16()).as_bytes().to_vec(),
+            image::ExtendedColorType::Rgb16,
+        ),
+        image::ColorType::Rgba16 => (
+            DynamicImage::ImageRgba16(img.to_rgba16()).as_bytes().to_vec(),
+            image::ExtendedColorType::Rgba16,
+        ),
+        _ => (img.to_rgba8().into_raw(), image::ExtendedColorType::Rgba8),
+    }
+}
+
+/// Encode an image to a specific format in memory, honoring quality/compression options.
+/// Formats with no tunable parameters fall back to `encode_image`'s library defaults.
+pub fn encode_image_with_options(
+    img: &DynamicImage,
+    format: ImageFormat,
+    options: &EncodeOptions,
+) -> Result<Vec<u8>, ImageError> {
+    use image::ImageEncoder;
+    use image::codecs::jpeg::JpegEncoder;
+    use image::codecs::png::PngEncoder;
+
+    let (width, height) = (img.width(), img.height());
+    let mut buffer = Vec::new();
+
+    match format {
+        ImageFormat::Jpeg => {
+            let (bytes, color_type) = jpeg_compatible_bytes(img);
+            let encoder =
+                JpegEncoder::new_with_quality(&mut buffer, options.jpeg_quality.clamp(1, 100));
+            encoder.write_image(&bytes, width, height, color_type)?;
+            Ok(buffer)
+        }
+        ImageFormat::Png => {
+            let (bytes, color_type) = png_compatible_bytes(img);
+            let encoder = PngEncoder::new_with_quality(
+                &mut buffer,
+                png_compression_type(options.png_compression),
+                png_filter_type(options.png_filter),
+            );
+            encoder.write_image(&bytes, width, height, color_type)?;
+            Ok(buffer)
+        }
+        _ => encode_image(img, format),
+    }
+}
+
 /// Resize an image
 pub fn resize_image(
     img: &DynamicImage,
@@ -220,14 +425,319 @@ pub fn invert(img: &mut DynamicImage) {
     img.invert();
 }
 
+#[allow(dead_code)]
+/// Color space for `fast_image_convert_colorspace`. Non-RGB variants are packed into the
+/// nearest 8-bit `DynamicImage` buffer (three channels, or four for Cmyk's C/M/Y/K).
+#[repr(u32)]
+pub enum ColorSpaceEnum {
+    Rgb = 0,
+    Hsl = 1,
+    Hsv = 2,
+    YCbCr = 3,
+    Lab = 4,
+    Cmyk = 5,
+}
+
+fn rgb_to_hsl(r: u8, g: u8, b: u8) -> (u8, u8, u8) {
+    let (r, g, b) = (r as f32 / 255.0, g as f32 / 255.0, b as f32 / 255.0);
+    let max = r.max(g).max(b);
+    let min = r.min(g).min(b);
+    let l = (max + min) / 2.0;
+
+    if (max - min).abs() < f32::EPSILON {
+        return (0, 0, (l * 255.0).round() as u8);
+    }
+
+    let delta = max - min;
+    let s = if l > 0.5 {
+        delta / (2.0 - max - min)
+    } else {
+        delta / (max + min)
+    };
+
+    let h = if max == r {
+        (g - b) / delta + if g < b { 6.0 } else { 0.0 }
+    } else if max == g {
+        (b - r) / delta + 2.0
+    } else {
+        (r - g) / delta + 4.0
+    } / 6.0;
+
+    ((h * 255.0).round() as u8, (s * 255.0).round() as u8, (l * 255.0).round() as u8)
+}
+
+fn hsl_to_rgb(h: u8, s: u8, l: u8) -> (u8, u8, u8) {
+    let (h, s, l) = (h as f32 / 255.0, s as f32 / 255.0, l as f32 / 255.0);
+    if s == 0.0 {
+        let v = (l * 255.0).round() as u8;
+        return (v, v, v);
+    }
+
+    let q = if l < 0.5 { l * (1.0 + s) } else { l + s - l * s };
+    let p = 2.0 * l - q;
+
+    let hue_to_rgb = |p: f32, q: f32, mut t: f32| -> f32 {
+        if t < 0.0 {
+            t += 1.0;
+        }
+        if t > 1.0 {
+            t -= 1.0;
+        }
+        if t < 1.0 / 6.0 {
+            return p + (q - p) * 6.0 * t;
+        }
+        if t < 1.0 / 2.0 {
+            return q;
+        }
+        if t < 2.0 / 3.0 {
+            return p + (q - p) * (2.0 / 3.0 - t) * 6.0;
+        }
+        p
+    };
+
+    let r = hue_to_rgb(p, q, h + 1.0 / 3.0);
+    let g = hue_to_rgb(p, q, h);
+    let b = hue_to_rgb(p, q, h - 1.0 / 3.0);
+
+    ((r * 255.0).round() as u8, (g * 255.0).round() as u8, (b * 255.0).round() as u8)
+}
+
+fn rgb_to_hsv(r: u8, g: u8, b: u8) -> (u8, u8, u8) {
+    let (rf, gf, bf) = (r as f32 / 255.0, g as f32 / 255.0, b as f32 / 255.0);
+    let max = rf.max(gf).max(bf);
+    let min = rf.min(gf).min(bf);
+    let delta = max - min;
+
+    let v = max;
+    let s = if max == 0.0 { 0.0 } else { delta / max };
+
+    let h = if delta == 0.0 {
+        0.0
+    } else if max == rf {
+        ((gf - bf) / delta).rem_euclid(6.0)
+    } else if max == gf {
+        (bf - rf) / delta + 2.0
+    } else {
+        (rf - gf) / delta + 4.0
+    } / 6.0;
+
+    ((h * 255.0).round() as u8, (s * 255.0).round() as u8, (v * 255.0).round() as u8)
+}
+
+fn hsv_to_rgb(h: u8, s: u8, v: u8) -> (u8, u8, u8) {
+    let (h, s, v) = (h as f32 / 255.0 * 6.0, s as f32 / 255.0, v as f32 / 255.0);
+    let i = h.floor();
+    let f = h - i;
+    let p = v * (1.0 - s);
+    let q = v * (1.0 - s * f);
+    let t = v * (1.0 - s * (1.0 - f));
+
+    let (r, g, b) = match i as i32 % 6 {
+        0 => (v, t, p),
+        1 => (q, v, p),
+        2 => (p, v, t),
+        3 => (p, q, v),
+        4 => (t, p, v),
+        _ => (v, p, q),
+    };
+
+    ((r * 255.0).round() as u8, (g * 255.0).round() as u8, (b * 255.0).round() as u8)
+}
+
+fn rgb_to_ycbcr(r: u8, g: u8, b: u8) -> (u8, u8, u8) {
+    let (rf, gf, bf) = (r as f32, g as f32, b as f32);
+    let y = 0.299 * rf + 0.587 * gf + 0.114 * bf;
+    let cb = 128.0 + (bf - y) * 0.564;
+    let cr = 128.0 + (rf - y) * 0.713;
+
+    (y.round().clamp(0.0, 255.0) as u8, cb.round().clamp(0.0, 255.0) as u8, cr.round().clamp(0.0, 255.0) as u8)
+}
+
+fn ycbcr_to_rgb(y: u8, cb: u8, cr: u8) -> (u8, u8, u8) {
+    let (y, cb, cr) = (y as f32, cb as f32 - 128.0, cr as f32 - 128.0);
+    let r = y + 1.402 * cr;
+    let g = y - 0.344 * cb - 0.714 * cr;
+    let b = y + 1.772 * cb;
+
+    (r.round().clamp(0.0, 255.0) as u8, g.round().clamp(0.0, 255.0) as u8, b.round().clamp(0.0, 255.0) as u8)
+}
+
+fn rgb_to_lab(r: u8, g: u8, b: u8) -> (u8, u8, u8) {
+    let to_linear = |c: u8| {
+        let c = c as f32 / 255.0;
+        if c > 0.04045 { ((c + 0.055) / 1.055).powf(2.4) } else { c / 12.92 }
+    };
+
+    let (rl, gl, bl) = (to_linear(r), to_linear(g), to_linear(b));
+    let x = rl * 0.4124 + gl * 0.3576 + bl * 0.1805;
+    let y = rl * 0.2126 + gl * 0.7152 + bl * 0.0722;
+    let z = rl * 0.0193 + gl * 0.1192 + bl * 0.9505;
+
+    let (xn, yn, zn) = (0.95047, 1.0, 1.08883);
+    let f = |t: f32| if t > 0.008856 { t.cbrt() } else { 7.787 * t + 16.0 / 116.0 };
+    let (fx, fy, fz) = (f(x / xn), f(y / yn), f(z / zn));
+
+    let l = 116.0 * fy - 16.0;
+    let a = 500.0 * (fx - fy);
+    let bb = 200.0 * (fy - fz);
+
+    ((l / 100.0 * 255.0).round().clamp(0.0, 255.0) as u8, (a + 128.0).round().clamp(0.0, 255.0) as u8, (bb + 128.0).round().clamp(0.0, 255.0) as u8)
+}
+
+fn lab_to_rgb(l: u8, a: u8, b: u8) -> (u8, u8, u8) {
+    let l = l as f32 / 255.0 * 100.0;
+    let a = a as f32 - 128.0;
+    let b = b as f32 - 128.0;
+
+    let fy = (l + 16.0) / 116.0;
+    let fx = fy + a / 500.0;
+    let fz = fy - b / 200.0;
+
+    let finv = |t: f32| if t.powi(3) > 0.008856 { t.powi(3) } else { (t - 16.0 / 116.0) / 7.787 };
+    let (xn, yn, zn) = (0.95047, 1.0, 1.08883);
+    let x = finv(fx) * xn;
+    let y = finv(fy) * yn;
+    let z = finv(fz) * zn;
+
+    let rl = x * 3.2406 + y * -1.5372 + z * -0.4986;
+    let gl = x * -0.9689 + y * 1.8758 + z * 0.0415;
+    let bl = x * 0.0557 + y * -0.2040 + z * 1.0570;
+
+    let to_srgb = |c: f32| {
+        let c = c.clamp(0.0, 1.0);
+        if c > 0.0031308 { 1.055 * c.powf(1.0 / 2.4) - 0.055 } else { c * 12.92 }
+    };
+
+    (
+        (to_srgb(rl) * 255.0).round().clamp(0.0, 255.0) as u8,
+        (to_srgb(gl) * 255.0).round().clamp(0.0, 255.0) as u8,
+        (to_srgb(bl) * 255.0).round().clamp(0.0, 255.0) as u8,
+    )
+}
+
+fn rgb_to_cmyk(r: u8, g: u8, b: u8) -> (u8, u8, u8, u8) {
+    let (rf, gf, bf) = (r as f32 / 255.0, g as f32 / 255.0, b as f32 / 255.0);
+    let k = 1.0 - rf.max(gf).max(bf);
+
+    if k >= 1.0 {
+        return (0, 0, 0, 255);
+    }
+
+    let c = (1.0 - rf - k) / (1.0 - k);
+    let m = (1.0 - gf - k) / (1.0 - k);
+    let y = (1.0 - bf - k) / (1.0 - k);
+
+    ((c * 255.0).round() as u8, (m * 255.0).round() as u8, (y * 255.0).round() as u8, (k * 255.0).round() as u8)
+}
+
+fn cmyk_to_rgb(c: u8, m: u8, y: u8, k: u8) -> (u8, u8, u8) {
+    let (cf, mf, yf, kf) = (c as f32 / 255.0, m as f32 / 255.0, y as f32 / 255.0, k as f32 / 255.0);
+    let r = 255.0 * (1.0 - cf) * (1.0 - kf);
+    let g = 255.0 * (1.0 - mf) * (1.0 - kf);
+    let b = 255.0 * (1.0 - yf) * (1.0 - kf);
+
+    (r.round() as u8, g.round() as u8, b.round() as u8)
+}
+
+/// Decode a buffer previously packed by `convert_colorspace` back into true RGB8 pixels
+fn decode_colorspace(img: &DynamicImage, space: &ColorSpaceEnum) -> image::RgbImage {
+    match space {
+        ColorSpaceEnum::Rgb => img.to_rgb8(),
+        ColorSpaceEnum::Hsl => map_rgb8(img, |(r, g, b)| hsl_to_rgb(r, g, b)),
+        ColorSpaceEnum::Hsv => map_rgb8(img, |(r, g, b)| hsv_to_rgb(r, g, b)),
+        ColorSpaceEnum::YCbCr => map_rgb8(img, |(r, g, b)| ycbcr_to_rgb(r, g, b)),
+        ColorSpaceEnum::Lab => map_rgb8(img, |(r, g, b)| lab_to_rgb(r, g, b)),
+        ColorSpaceEnum::Cmyk => {
+            let rgba = img.to_rgba8();
+            image::RgbImage::from_fn(rgba.width(), rgba.height(), |x, y| {
+                let p = rgba.get_pixel(x, y).0;
+                let (r, g, b) = cmyk_to_rgb(p[0], p[1], p[2], p[3]);
+                image::Rgb([r, g, b])
+            })
+        }
+    }
+}
+
+fn map_rgb8(img: &DynamicImage, f: impl Fn((u8, u8, u8)) -> (u8, u8, u8)) -> image::RgbImage {
+    let src = img.to_rgb8();
+    image::RgbImage::from_fn(src.width(), src.height(), |x, y| {
+        let p = src.get_pixel(x, y).0;
+        let (r, g, b) = f((p[0], p[1], p[2]));
+        image::Rgb([r, g, b])
+    })
+}
+
+/// Convert an image between color spaces, packing the result into the nearest 8-bit
+/// `DynamicImage` buffer.
+///
+/// NOTE: this takes an explicit `source` in addition to `target`, which is one parameter
+/// more than originally requested. A plain `ImageHandle` has no way to record which space
+/// its bytes are currently packed in, so a single-`target` signature can't invert a prior
+/// conversion correctly (e.g. Hsv -> Rgb needs to know the buffer holds Hsv, not Rgb,
+/// bytes). Flagging this deviation rather than reinterpreting the signature silently —
+/// `source` describes the space the input handle currently holds (`Rgb` for a normal
+/// image, or whatever space a prior call to this function produced).
+pub fn convert_colorspace(
+    img: &DynamicImage,
+    source: &ColorSpaceEnum,
+    target: &ColorSpaceEnum,
+) -> DynamicImage {
+    let rgb = decode_colorspace(img, source);
+
+    match target {
+        ColorSpaceEnum::Rgb => DynamicImage::ImageRgb8(rgb),
+        ColorSpaceEnum::Hsl => {
+            DynamicImage::ImageRgb8(image::RgbImage::from_fn(rgb.width(), rgb.height(), |x, y| {
+                let p = rgb.get_pixel(x, y).0;
+                let (h, s, l) = rgb_to_hsl(p[0], p[1], p[2]);
+                image::Rgb([h, s, l])
+            }))
+        }
+        ColorSpaceEnum::Hsv => {
+            DynamicImage::ImageRgb8(image::RgbImage::from_fn(rgb.width(), rgb.height(), |x, y| {
+                let p = rgb.get_pixel(x, y).0;
+                let (h, s, v) = rgb_to_hsv(p[0], p[1], p[2]);
+                image::Rgb([h, s, v])
+            }))
+        }
+        ColorSpaceEnum::YCbCr => {
+            DynamicImage::ImageRgb8(image::RgbImage::from_fn(rgb.width(), rgb.height(), |x, y| {
+                let p = rgb.get_pixel(x, y).0;
+                let (y_, cb, cr) = rgb_to_ycbcr(p[0], p[1], p[2]);
+                image::Rgb([y_, cb, cr])
+            }))
+        }
+        ColorSpaceEnum::Lab => {
+            DynamicImage::ImageRgb8(image::RgbImage::from_fn(rgb.width(), rgb.height(), |x, y| {
+                let p = rgb.get_pixel(x, y).0;
+                let (l, a, b) = rgb_to_lab(p[0], p[1], p[2]);
+                image::Rgb([l, a, b])
+            }))
+        }
+        ColorSpaceEnum::Cmyk => {
+            DynamicImage::ImageRgba8(image::RgbaImage::from_fn(rgb.width(), rgb.height(), |x, y| {
+                let p = rgb.get_pixel(x, y).0;
+                let (c, m, ye, k) = rgb_to_cmyk(p[0], p[1], p[2]);
+                image::Rgba([c, m, ye, k])
+            }))
+        }
+    }
+}
+
 /// Get image metadata
 pub fn get_metadata(img: &DynamicImage) -> ImageMetadata {
     let color_type = match img.color() {
-        image::ColorType::L8 | image::ColorType::L16 => 0,
-        image::ColorType::La8 | image::ColorType::La16 => 1,
-        image::ColorType::Rgb8 | image::ColorType::Rgb16 | image::ColorType::Rgb32F => 2,
-        image::ColorType::Rgba8 | image::ColorType::Rgba16 | image::ColorType::Rgba32F => 3,
-        _ => 3, // Default to RGBA
+        image::ColorType::L8 => 0,
+        image::ColorType::La8 => 1,
+        image::ColorType::Rgb8 => 2,
+        image::ColorType::Rgba8 => 3,
+        image::ColorType::L16 => 4,
+        image::ColorType::La16 => 5,
+        image::ColorType::Rgb16 => 6,
+        image::ColorType::Rgba16 => 7,
+        image::ColorType::Rgb32F => 8,
+        image::ColorType::Rgba32F => 9,
+        _ => 3, // Default to RGBA8
     };
 
     ImageMetadata {
@@ -237,6 +747,52 @@ pub fn get_metadata(img: &DynamicImage) -> ImageMetadata {
     }
 }
 
+/// Get a copy of an image's raw interleaved pixel bytes, alongside its color type code
+/// (matching `ImageMetadata.color_type`: see its field doc for the full 0-9 code range)
+pub fn get_raw_pixels(img: &DynamicImage) -> (Vec<u8>, u8) {
+    (img.as_bytes().to_vec(), get_metadata(img).color_type)
+}
+
+/// Channels per pixel for the raw color type codes accepted by `from_raw_pixels`
+fn channels_for_color_type(color_type: u8) -> Option<u32> {
+    match color_type {
+        0 => Some(1), // L8
+        1 => Some(2), // La8
+        2 => Some(3), // Rgb8
+        3 => Some(4), // Rgba8
+        _ => None,
+    }
+}
+
+/// Build a `DynamicImage` from an externally supplied buffer of interleaved 8-bit samples
+pub fn from_raw_pixels(
+    data: &[u8],
+    width: u32,
+    height: u32,
+    color_type: u8,
+) -> Result<DynamicImage, ImageErrorCode> {
+    let channels = channels_for_color_type(color_type).ok_or(ImageErrorCode::UnsupportedFormat)?;
+    let expected_len = (width as usize)
+        .checked_mul(height as usize)
+        .and_then(|pixels| pixels.checked_mul(channels as usize))
+        .ok_or(ImageErrorCode::InvalidDimensions)?;
+    if data.len() != expected_len {
+        return Err(ImageErrorCode::InvalidDimensions);
+    }
+
+    let image = match color_type {
+        0 => image::GrayImage::from_raw(width, height, data.to_vec()).map(DynamicImage::ImageLuma8),
+        1 => {
+            image::GrayAlphaImage::from_raw(width, height, data.to_vec()).map(DynamicImage::ImageLumaA8)
+        }
+        2 => image::RgbImage::from_raw(width, height, data.to_vec()).map(DynamicImage::ImageRgb8),
+        3 => image::RgbaImage::from_raw(width, height, data.to_vec()).map(DynamicImage::ImageRgba8),
+        _ => unreachable!(),
+    };
+
+    image.ok_or(ImageErrorCode::InvalidDimensions)
+}
+
 /// Guess image format from byte data
 pub fn guess_image_format(data: &[u8]) -> Result<ImageFormatEnum, ImageError> {
     let format = image::guess_format(data)?;
@@ -260,3 +816,70 @@ pub fn error_to_code(err: &ImageError) -> ImageErrorCode {
         _ => ImageErrorCode::Unknown,
     }
 }
+
+/// A single recorded step in a `Pipeline`
+enum PipelineOp {
+    Resize { width: u32, height: u32, filter: FilterType },
+    Blur { sigma: f32 },
+    Crop { x: u32, y: u32, width: u32, height: u32 },
+    Grayscale,
+}
+
+/// An ordered chain of operations recorded once and replayed over many images, in parallel
+/// via `apply_batch`. Collapses what would otherwise be N round-trips through the FFI boundary
+/// per image into a single call.
+pub struct Pipeline {
+    ops: Vec<PipelineOp>,
+}
+
+impl Default for Pipeline {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Pipeline {
+    pub fn new() -> Self {
+        Pipeline { ops: Vec::new() }
+    }
+
+    pub fn push_resize(&mut self, width: u32, height: u32, filter: FilterType) {
+        self.ops.push(PipelineOp::Resize { width, height, filter });
+    }
+
+    pub fn push_blur(&mut self, sigma: f32) {
+        self.ops.push(PipelineOp::Blur { sigma });
+    }
+
+    pub fn push_crop(&mut self, x: u32, y: u32, width: u32, height: u32) {
+        self.ops.push(PipelineOp::Crop { x, y, width, height });
+    }
+
+    pub fn push_grayscale(&mut self) {
+        self.ops.push(PipelineOp::Grayscale);
+    }
+
+    /// Run the recorded chain over a single image
+    pub fn apply(&self, img: &DynamicImage) -> DynamicImage {
+        let mut current = img.clone();
+        for op in &self.ops {
+            current = match op {
+                PipelineOp::Resize { width, height, filter } => {
+                    resize_image(&current, *width, *height, *filter)
+                }
+                PipelineOp::Blur { sigma } => blur_image(&current, *sigma),
+                PipelineOp::Crop { x, y, width, height } => {
+                    crop_image(&current, *x, *y, *width, *height)
+                }
+                PipelineOp::Grayscale => grayscale(&current),
+            };
+        }
+        current
+    }
+
+    /// Run the recorded chain over every image in parallel, saturating all cores
+    pub fn apply_batch(&self, images: &[&DynamicImage]) -> Vec<DynamicImage> {
+        use rayon::prelude::*;
+        images.par_iter().map(|img| self.apply(img)).collect()
+    }
+}
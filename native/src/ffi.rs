@@ -38,6 +38,26 @@ pub extern "C" fn fast_image_free(handle: *mut ImageHandle) {
     }
 }
 
+/// Free an animation handle
+#[unsafe(no_mangle)]
+pub extern "C" fn fast_image_free_animation(handle: *mut AnimationHandle) {
+    if !handle.is_null() {
+        unsafe {
+            let _ = Box::from_raw(handle as *mut Vec<AnimationFrame>);
+        }
+    }
+}
+
+/// Free a pipeline handle
+#[unsafe(no_mangle)]
+pub extern "C" fn fast_image_free_pipeline(handle: *mut PipelineHandle) {
+    if !handle.is_null() {
+        unsafe {
+            let _ = Box::from_raw(handle as *mut Pipeline);
+        }
+    }
+}
+
 // ============================================================================
 // Image Loading
 // ============================================================================
@@ -129,6 +149,88 @@ pub extern "C" fn fast_image_guess_format(
     }
 }
 
+// ============================================================================
+// Animation Decoding
+// ============================================================================
+
+/// Load an animated GIF/WebP from a file path
+/// Returns null on error
+#[unsafe(no_mangle)]
+pub extern "C" fn fast_image_load_animation(path: *const c_char) -> *mut AnimationHandle {
+    if path.is_null() {
+        return std::ptr::null_mut();
+    }
+
+    let path_str = unsafe {
+        match CStr::from_ptr(path).to_str() {
+            Ok(s) => s,
+            Err(_) => return std::ptr::null_mut(),
+        }
+    };
+
+    match load_animation(path_str) {
+        Ok(frames) => Box::into_raw(Box::new(frames)) as *mut AnimationHandle,
+        Err(_) => std::ptr::null_mut(),
+    }
+}
+
+/// Load an animated GIF/WebP from a memory buffer
+/// Returns null on error
+#[unsafe(no_mangle)]
+pub extern "C" fn fast_image_load_animation_from_memory(
+    data: *const u8,
+    len: usize,
+) -> *mut AnimationHandle {
+    if data.is_null() || len == 0 {
+        return std::ptr::null_mut();
+    }
+
+    let buffer = unsafe { slice::from_raw_parts(data, len) };
+
+    match load_animation_from_memory(buffer) {
+        Ok(frames) => Box::into_raw(Box::new(frames)) as *mut AnimationHandle,
+        Err(_) => std::ptr::null_mut(),
+    }
+}
+
+/// Number of frames in a decoded animation
+#[unsafe(no_mangle)]
+pub extern "C" fn fast_image_animation_frame_count(handle: *const AnimationHandle) -> usize {
+    if handle.is_null() {
+        return 0;
+    }
+
+    let frames = unsafe { &*(handle as *const Vec<AnimationFrame>) };
+    frames.len()
+}
+
+/// Get a single animation frame as a standalone image handle, writing its delay in milliseconds
+/// to `out_delay_ms`. Returns null if the handle or index is invalid.
+#[unsafe(no_mangle)]
+pub extern "C" fn fast_image_animation_get_frame(
+    handle: *const AnimationHandle,
+    index: usize,
+    out_delay_ms: *mut u32,
+) -> *mut ImageHandle {
+    if handle.is_null() {
+        return std::ptr::null_mut();
+    }
+
+    let frames = unsafe { &*(handle as *const Vec<AnimationFrame>) };
+    let frame = match frames.get(index) {
+        Some(frame) => frame,
+        None => return std::ptr::null_mut(),
+    };
+
+    if !out_delay_ms.is_null() {
+        unsafe {
+            *out_delay_ms = frame.delay_ms;
+        }
+    }
+
+    Box::into_raw(Box::new(frame.image.clone())) as *mut ImageHandle
+}
+
 // ============================================================================
 // Image Saving
 // ============================================================================
@@ -189,6 +291,48 @@ pub extern "C" fn fast_image_encode(
     }
 }
 
+/// Encode an image to a buffer in the specified format, honoring quality/compression options.
+/// Passing a null `options` pointer uses `EncodeOptions::default()` (JPEG quality 80,
+/// default PNG compression, adaptive PNG filter).
+/// Caller must free the buffer using fast_image_free_buffer
+#[unsafe(no_mangle)]
+pub extern "C" fn fast_image_encode_with_options(
+    handle: *const ImageHandle,
+    format: ImageFormatEnum,
+    options: *const EncodeOptions,
+    out_data: *mut *mut u8,
+    out_len: *mut usize,
+) -> ImageErrorCode {
+    if handle.is_null() || out_data.is_null() || out_len.is_null() {
+        return ImageErrorCode::InvalidPointer;
+    }
+
+    let img = unsafe { &*(handle as *const DynamicImage) };
+    let owned_options;
+    let options = if options.is_null() {
+        owned_options = EncodeOptions::default();
+        &owned_options
+    } else {
+        unsafe { &*options }
+    };
+
+    match encode_image_with_options(img, format.to_image_format(), options) {
+        Ok(buffer) => {
+            let mut boxed = buffer.into_boxed_slice();
+            let len = boxed.len();
+            let ptr = boxed.as_mut_ptr();
+            std::mem::forget(boxed);
+
+            unsafe {
+                *out_data = ptr;
+                *out_len = len;
+            }
+            ImageErrorCode::Success
+        }
+        Err(e) => error_to_code(&e),
+    }
+}
+
 // ============================================================================
 // Image Information
 // ============================================================================
@@ -213,6 +357,68 @@ pub extern "C" fn fast_image_get_metadata(
     ImageErrorCode::Success
 }
 
+// ============================================================================
+// Raw Pixel Access
+// ============================================================================
+
+/// Get a freshly allocated copy of an image's raw interleaved pixel bytes
+/// Caller must free the buffer using fast_image_free_buffer
+#[unsafe(no_mangle)]
+pub extern "C" fn fast_image_get_raw_pixels(
+    handle: *const ImageHandle,
+    out_data: *mut *mut u8,
+    out_len: *mut usize,
+    out_color_type: *mut u8,
+) -> ImageErrorCode {
+    if handle.is_null() || out_data.is_null() || out_len.is_null() || out_color_type.is_null() {
+        return ImageErrorCode::InvalidPointer;
+    }
+
+    let img = unsafe { &*(handle as *const DynamicImage) };
+    let (pixels, color_type) = get_raw_pixels(img);
+
+    let mut boxed = pixels.into_boxed_slice();
+    let len = boxed.len();
+    let ptr = boxed.as_mut_ptr();
+    std::mem::forget(boxed);
+
+    unsafe {
+        *out_data = ptr;
+        *out_len = len;
+        *out_color_type = color_type;
+    }
+
+    ImageErrorCode::Success
+}
+
+/// Build an image from a raw buffer of interleaved 8-bit samples (L8/La8/Rgb8/Rgba8,
+/// per `ImageMetadata.color_type` codes). `len` must equal `width * height * channels`.
+#[unsafe(no_mangle)]
+pub extern "C" fn fast_image_from_raw(
+    data: *const u8,
+    len: usize,
+    width: u32,
+    height: u32,
+    color_type: u8,
+    out_handle: *mut *mut ImageHandle,
+) -> ImageErrorCode {
+    if data.is_null() || out_handle.is_null() {
+        return ImageErrorCode::InvalidPointer;
+    }
+
+    let buffer = unsafe { slice::from_raw_parts(data, len) };
+
+    match from_raw_pixels(buffer, width, height, color_type) {
+        Ok(img) => {
+            unsafe {
+                *out_handle = Box::into_raw(Box::new(img)) as *mut ImageHandle;
+            }
+            ImageErrorCode::Success
+        }
+        Err(code) => code,
+    }
+}
+
 // ============================================================================
 // Image Transformations
 // ============================================================================
@@ -423,3 +629,152 @@ pub extern "C" fn fast_image_invert(handle: *mut ImageHandle) -> ImageErrorCode
 
     ImageErrorCode::Success
 }
+
+// ============================================================================
+// Color Space Conversion
+// ============================================================================
+
+/// Convert an image from one color space to another, returning a new handle whose
+/// channels hold the converted values. `source` describes the space the input handle
+/// currently holds — see `convert_colorspace` in `api.rs` for why it's needed.
+#[unsafe(no_mangle)]
+pub extern "C" fn fast_image_convert_colorspace(
+    handle: *const ImageHandle,
+    source: ColorSpaceEnum,
+    target: ColorSpaceEnum,
+) -> *mut ImageHandle {
+    if handle.is_null() {
+        return std::ptr::null_mut();
+    }
+
+    let img = unsafe { &*(handle as *const DynamicImage) };
+    let converted = convert_colorspace(img, &source, &target);
+
+    Box::into_raw(Box::new(converted)) as *mut ImageHandle
+}
+
+// ============================================================================
+// Batch Pipeline
+// ============================================================================
+
+/// Create an empty pipeline to record operations into
+#[unsafe(no_mangle)]
+pub extern "C" fn fast_image_pipeline_new() -> *mut PipelineHandle {
+    Box::into_raw(Box::new(Pipeline::new())) as *mut PipelineHandle
+}
+
+/// Record a resize step
+#[unsafe(no_mangle)]
+pub extern "C" fn fast_image_pipeline_push_resize(
+    handle: *mut PipelineHandle,
+    width: u32,
+    height: u32,
+    filter: FilterTypeEnum,
+) -> ImageErrorCode {
+    if handle.is_null() {
+        return ImageErrorCode::InvalidPointer;
+    }
+
+    let pipeline = unsafe { &mut *(handle as *mut Pipeline) };
+    pipeline.push_resize(width, height, filter.to_filter_type());
+
+    ImageErrorCode::Success
+}
+
+/// Record a blur step
+#[unsafe(no_mangle)]
+pub extern "C" fn fast_image_pipeline_push_blur(
+    handle: *mut PipelineHandle,
+    sigma: f32,
+) -> ImageErrorCode {
+    if handle.is_null() {
+        return ImageErrorCode::InvalidPointer;
+    }
+
+    let pipeline = unsafe { &mut *(handle as *mut Pipeline) };
+    pipeline.push_blur(sigma);
+
+    ImageErrorCode::Success
+}
+
+/// Record a crop step
+#[unsafe(no_mangle)]
+pub extern "C" fn fast_image_pipeline_push_crop(
+    handle: *mut PipelineHandle,
+    x: u32,
+    y: u32,
+    width: u32,
+    height: u32,
+) -> ImageErrorCode {
+    if handle.is_null() {
+        return ImageErrorCode::InvalidPointer;
+    }
+
+    let pipeline = unsafe { &mut *(handle as *mut Pipeline) };
+    pipeline.push_crop(x, y, width, height);
+
+    ImageErrorCode::Success
+}
+
+/// Record a grayscale step
+#[unsafe(no_mangle)]
+pub extern "C" fn fast_image_pipeline_push_grayscale(handle: *mut PipelineHandle) -> ImageErrorCode {
+    if handle.is_null() {
+        return ImageErrorCode::InvalidPointer;
+    }
+
+    let pipeline = unsafe { &mut *(handle as *mut Pipeline) };
+    pipeline.push_grayscale();
+
+    ImageErrorCode::Success
+}
+
+/// Run the recorded pipeline over a single image
+#[unsafe(no_mangle)]
+pub extern "C" fn fast_image_pipeline_apply(
+    handle: *const PipelineHandle,
+    image: *const ImageHandle,
+) -> *mut ImageHandle {
+    if handle.is_null() || image.is_null() {
+        return std::ptr::null_mut();
+    }
+
+    let pipeline = unsafe { &*(handle as *const Pipeline) };
+    let img = unsafe { &*(image as *const DynamicImage) };
+
+    Box::into_raw(Box::new(pipeline.apply(img))) as *mut ImageHandle
+}
+
+/// Run the recorded pipeline over `count` images in parallel, writing one output handle per
+/// input into `out_images` (which must have room for `count` pointers)
+#[unsafe(no_mangle)]
+pub extern "C" fn fast_image_pipeline_apply_batch(
+    handle: *const PipelineHandle,
+    images: *const *const ImageHandle,
+    count: usize,
+    out_images: *mut *mut ImageHandle,
+) -> ImageErrorCode {
+    if handle.is_null() || images.is_null() || out_images.is_null() {
+        return ImageErrorCode::InvalidPointer;
+    }
+
+    let pipeline = unsafe { &*(handle as *const Pipeline) };
+    let image_handles = unsafe { slice::from_raw_parts(images, count) };
+
+    if image_handles.iter().any(|handle| handle.is_null()) {
+        return ImageErrorCode::InvalidPointer;
+    }
+
+    let inputs: Vec<&DynamicImage> = image_handles
+        .iter()
+        .map(|handle| unsafe { &*(*handle as *const DynamicImage) })
+        .collect();
+
+    let results = pipeline.apply_batch(&inputs);
+    let outputs = unsafe { slice::from_raw_parts_mut(out_images, count) };
+    for (slot, image) in outputs.iter_mut().zip(results) {
+        *slot = Box::into_raw(Box::new(image)) as *mut ImageHandle;
+    }
+
+    ImageErrorCode::Success
+}